@@ -1,6 +1,7 @@
 use crate::Shape;
-use bevy::prelude::{Reflect, Vec3};
-use rand::Rng;
+use bevy::prelude::{Color, Reflect, Vec3};
+use rand::{Rng, RngCore, SeedableRng};
+use rand_pcg::Pcg32;
 use std::fmt::Debug;
 
 pub mod shape_enum;
@@ -68,18 +69,86 @@ pub enum EmitterDirectionMode {
     Automatic,
     /// All particles will have a fixed direction
     Fixed(Vec3),
+    /// The direction follows the emission surface's normal at the particle's spawn point,
+    /// instead of the vector from the shape's nominal center. Shapes without normal data
+    /// (or sampling a point with none) fall back to the `Automatic` behaviour.
+    SurfaceNormal,
 }
 
 #[derive(Debug, Clone)]
 pub struct EmittedParticle {
     pub position: Vec3,
     pub direction: Vec3,
+    /// Initial particle color sampled from the emitter's [`EmissionRamp`], if any
+    pub color: Option<Color>,
+    /// Initial particle scale sampled from the emitter's [`EmissionRamp`], if any
+    pub scale: Option<f32>,
+}
+
+/// A single stop in an [`EmissionRamp`], at a given key in `[0, 1]`
+#[derive(Debug, Copy, Clone, Reflect)]
+#[cfg_attr(feature = "inspector", derive(bevy_inspector_egui::Inspectable))]
+pub struct EmissionRampStop {
+    /// Position of this stop along the ramp, in `[0, 1]`
+    #[cfg_attr(feature = "inspector", inspectable(min = 0.0, max = 1.0))]
+    pub key: f32,
+    /// Particle color at this stop
+    pub color: Color,
+    /// Particle scale at this stop
+    pub scale: f32,
+}
+
+/// Maps a `[0, 1]` emission key to an initial particle color and scale, piecewise-linearly
+/// interpolated between stops, inspired by Godot's `ParticlesMaterial::color_ramp`
+#[derive(Debug, Clone, Reflect, Default)]
+#[cfg_attr(feature = "inspector", derive(bevy_inspector_egui::Inspectable))]
+pub struct EmissionRamp {
+    /// Stops defining the ramp, expected to be sorted by [`EmissionRampStop::key`]
+    pub stops: Vec<EmissionRampStop>,
+}
+
+impl EmissionRamp {
+    /// Samples the ramp at `key` (clamped to `[0, 1]`), returning `None` if it has no stops
+    pub fn sample(&self, key: f32) -> Option<(Color, f32)> {
+        let key = key.clamp(0.0, 1.0);
+        match self.stops.len() {
+            0 => None,
+            1 => Some((self.stops[0].color, self.stops[0].scale)),
+            _ => {
+                let next_index = self
+                    .stops
+                    .iter()
+                    .position(|stop| stop.key >= key)
+                    .unwrap_or(self.stops.len() - 1)
+                    .max(1);
+                let previous = &self.stops[next_index - 1];
+                let next = &self.stops[next_index];
+                let t = ((key - previous.key) / (next.key - previous.key).max(f32::EPSILON))
+                    .clamp(0.0, 1.0);
+                Some((
+                    lerp_color(previous.color, next.color, t),
+                    previous.scale + (next.scale - previous.scale) * t,
+                ))
+            }
+        }
+    }
+}
+
+fn lerp_color(from: Color, to: Color, t: f32) -> Color {
+    let from = from.as_rgba_f32();
+    let to = to.as_rgba_f32();
+    Color::rgba(
+        from[0] + (to[0] - from[0]) * t,
+        from[1] + (to[1] - from[1]) * t,
+        from[2] + (to[2] - from[2]) * t,
+        from[3] + (to[3] - from[3]) * t,
+    )
 }
 
 pub trait Emitter: Debug + Clone {
     fn emit_random_particle(
         &self,
-        rng: &mut impl Rng,
+        rng: &mut (impl Rng + ?Sized),
         thickness: f32,
         direction_mode: EmitterDirectionMode,
     ) -> EmittedParticle;
@@ -87,7 +156,7 @@ pub trait Emitter: Debug + Clone {
     fn spread_particle(
         &self,
         spread: &mut EmissionSpread,
-        rng: &mut impl Rng,
+        rng: &mut (impl Rng + ?Sized),
         thickness: f32,
         direction_mode: EmitterDirectionMode,
     ) -> EmittedParticle;
@@ -109,6 +178,19 @@ pub struct EmitterShape {
     pub direction_params: EmitterDirectionParams,
     /// Emission mode
     pub mode: EmissionMode,
+    /// Optional seed for this emitter's own random number generator.
+    /// When set, emission draws from a dedicated seeded PRNG instead of the shared `rng`
+    /// passed to [`EmitterShape::emit_particle`], so a given seed and frame count always
+    /// produce the same particles, enabling deterministic replays and lockstep multiplayer.
+    pub seed: Option<u64>,
+    /// Lazily-initialized PRNG state backing `seed`, alongside the seed it was created from
+    #[doc(hidden)]
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "inspector", inspectable(ignore))]
+    seeded_rng: Option<(u64, Pcg32)>,
+    /// Optional initial color/scale ramp, keyed on [`EmissionSpread::current_index`] in
+    /// [`EmissionMode::Spread`] or on a random `[0, 1]` draw in [`EmissionMode::Random`]
+    pub ramp: Option<EmissionRamp>,
 }
 
 impl EmissionSpread {
@@ -138,7 +220,23 @@ impl EmissionSpread {
 }
 
 impl EmitterShape {
-    pub(crate) fn emit_particle(&mut self, rng: &mut impl Rng) -> EmittedParticle {
+    /// Resets this emitter's seeded PRNG, so the next emitted particle restarts deterministically
+    /// from frame 0 of `seed` instead of continuing from wherever it last left off. Use this to
+    /// rewind a replay or resynchronize an emitter between networked clients.
+    pub fn reseed(&mut self) {
+        self.seeded_rng = None;
+    }
+
+    pub(crate) fn emit_particle(&mut self, rng: &mut (impl Rng + ?Sized)) -> EmittedParticle {
+        let rng: &mut dyn RngCore = match self.seed {
+            Some(seed) => {
+                if !matches!(self.seeded_rng, Some((active_seed, _)) if active_seed == seed) {
+                    self.seeded_rng = Some((seed, Pcg32::seed_from_u64(seed)));
+                }
+                &mut self.seeded_rng.as_mut().unwrap().1
+            }
+            None => rng,
+        };
         let mut particle = match &mut self.mode {
             EmissionMode::Random => self.shape.emit_random_particle(
                 rng,
@@ -171,6 +269,16 @@ impl EmitterShape {
                 .try_normalize()
                 .unwrap_or(Vec3::Y);
         }
+        if let Some(ramp) = &self.ramp {
+            let key = match &self.mode {
+                EmissionMode::Random => rng.gen_range(0.0..1.0),
+                EmissionMode::Spread(spread) => spread.current_index,
+            };
+            if let Some((color, scale)) = ramp.sample(key) {
+                particle.color = Some(color);
+                particle.scale = Some(scale);
+            }
+        }
         particle
     }
 }
@@ -188,6 +296,9 @@ impl Default for EmitterShape {
             thickness: 1.0,
             direction_params: EmitterDirectionParams::default(),
             mode: EmissionMode::default(),
+            seed: None,
+            seeded_rng: None,
+            ramp: None,
         }
     }
 }
@@ -231,6 +342,8 @@ impl Default for EmittedParticle {
         Self {
             position: Default::default(),
             direction: Vec3::Y,
+            color: None,
+            scale: None,
         }
     }
 }
@@ -1,9 +1,22 @@
 use crate::components::particle_emitter::emitter_shape::{EmittedParticle, Emitter};
 use crate::{EmissionSpread, EmitterDirectionMode};
 use bevy::prelude::{shape::Cube, Mesh, Vec3};
-use bevy::render::mesh::VertexAttributeValues;
+use bevy::render::mesh::{Indices, VertexAttributeValues};
 use rand::Rng;
 
+/// A single mesh triangle, cached alongside the running sum of areas up to and including it,
+/// so [`ConvexMesh`] can binary-search a uniform area draw into a triangle pick
+#[derive(Debug, Clone, Copy)]
+struct MeshTriangle {
+    v0: Vec3,
+    v1: Vec3,
+    v2: Vec3,
+    /// Per-vertex normals, present only if the mesh has `ATTRIBUTE_NORMAL` set
+    normals: Option<[Vec3; 3]>,
+    /// Sum of this triangle's area and every preceding triangle's area
+    cumulative_area: f32,
+}
+
 /// Initializes particles at randomly-sampled positions within a convex mesh and directs them outwards from the `nominal_center`
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "inspector", derive(bevy_inspector_egui::Inspectable))]
@@ -12,56 +25,179 @@ pub struct ConvexMesh {
     pub mesh: Mesh,
     /// The *nominal center* of the convex mesh
     pub nominal_center: Vec3,
+    /// Area-weighted triangle CDF built from `mesh`, cached so it isn't rebuilt every frame
+    #[doc(hidden)]
+    #[cfg_attr(feature = "inspector", inspectable(ignore))]
+    triangles: Vec<MeshTriangle>,
+    /// Sum of every triangle's area, i.e. `triangles.last().cumulative_area`
+    #[doc(hidden)]
+    #[cfg_attr(feature = "inspector", inspectable(ignore))]
+    total_area: f32,
+}
+
+impl ConvexMesh {
+    /// Creates a new [`ConvexMesh`] emitter, pre-computing its area-weighted triangle cache
+    pub fn new(mesh: Mesh, nominal_center: Vec3) -> Self {
+        let (triangles, total_area) = Self::build_triangle_cdf(&mesh);
+        Self {
+            mesh,
+            nominal_center,
+            triangles,
+            total_area,
+        }
+    }
+
+    /// Rebuilds the triangle CDF from the current `mesh`, e.g. after replacing it
+    pub fn refresh_cache(&mut self) {
+        let (triangles, total_area) = Self::build_triangle_cdf(&self.mesh);
+        self.triangles = triangles;
+        self.total_area = total_area;
+    }
+
+    fn build_triangle_cdf(mesh: &Mesh) -> (Vec<MeshTriangle>, f32) {
+        let Some(positions) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else {
+            return (Vec::new(), 0.0);
+        };
+        let VertexAttributeValues::Float32x3(positions) = positions else {
+            panic!("Expected a mesh with `Float32x3` positions");
+        };
+        let positions: Vec<Vec3> = positions.iter().copied().map(Vec3::from).collect();
+        let normals: Option<Vec<Vec3>> = match mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+            Some(VertexAttributeValues::Float32x3(normals)) => {
+                Some(normals.iter().copied().map(Vec3::from).collect())
+            }
+            _ => None,
+        };
+        let indices: Vec<u32> = match mesh.indices() {
+            Some(Indices::U16(indices)) => indices.iter().map(|i| *i as u32).collect(),
+            Some(Indices::U32(indices)) => indices.clone(),
+            None => (0..positions.len() as u32).collect(),
+        };
+        let mut triangles = Vec::with_capacity(indices.len() / 3);
+        let mut total_area = 0.0;
+        for triangle in indices.chunks_exact(3) {
+            let [i0, i1, i2] = [
+                triangle[0] as usize,
+                triangle[1] as usize,
+                triangle[2] as usize,
+            ];
+            let v0 = positions[i0];
+            let v1 = positions[i1];
+            let v2 = positions[i2];
+            let area = 0.5 * (v1 - v0).cross(v2 - v0).length();
+            if area <= f32::EPSILON {
+                continue;
+            }
+            total_area += area;
+            triangles.push(MeshTriangle {
+                v0,
+                v1,
+                v2,
+                normals: normals
+                    .as_ref()
+                    .map(|normals| [normals[i0], normals[i1], normals[i2]]),
+                cumulative_area: total_area,
+            });
+        }
+        (triangles, total_area)
+    }
+
+    /// Finds the triangle whose cumulative-area bucket contains `u`, for `u` in `[0, total_area)`
+    fn triangle_for_area(&self, u: f32) -> &MeshTriangle {
+        let index = self
+            .triangles
+            .partition_point(|triangle| triangle.cumulative_area <= u)
+            .min(self.triangles.len() - 1);
+        &self.triangles[index]
+    }
+
+    /// Picks a uniform point on `triangle` using the barycentric reflection trick, along with
+    /// the interpolated surface normal at that point if the mesh has normal data
+    fn sample_triangle(
+        triangle: &MeshTriangle,
+        rng: &mut (impl Rng + ?Sized),
+    ) -> (Vec3, Option<Vec3>) {
+        let (mut r1, mut r2): (f32, f32) = (rng.gen_range(0.0..1.0), rng.gen_range(0.0..1.0));
+        if r1 + r2 > 1.0 {
+            r1 = 1.0 - r1;
+            r2 = 1.0 - r2;
+        }
+        let position =
+            triangle.v0 + r1 * (triangle.v1 - triangle.v0) + r2 * (triangle.v2 - triangle.v0);
+        let normal = triangle.normals.map(|[n0, n1, n2]| {
+            (n0 + r1 * (n1 - n0) + r2 * (n2 - n0))
+                .try_normalize()
+                .unwrap_or(n0)
+        });
+        (position, normal)
+    }
+
+    fn direction_for(
+        &self,
+        position: Vec3,
+        normal: Option<Vec3>,
+        direction_mode: EmitterDirectionMode,
+    ) -> Vec3 {
+        match direction_mode {
+            EmitterDirectionMode::Automatic => (position - self.nominal_center)
+                .try_normalize()
+                .unwrap_or(Vec3::Y),
+            EmitterDirectionMode::Fixed(dir) => dir,
+            EmitterDirectionMode::SurfaceNormal => normal.unwrap_or_else(|| {
+                (position - self.nominal_center)
+                    .try_normalize()
+                    .unwrap_or(Vec3::Y)
+            }),
+        }
+    }
 }
 
 impl Emitter for ConvexMesh {
-    // TODO: use triangles ?
     fn emit_random_particle(
         &self,
-        rng: &mut impl Rng,
+        rng: &mut (impl Rng + ?Sized),
         thickness: f32,
         direction_mode: EmitterDirectionMode,
     ) -> EmittedParticle {
-        let mesh = &self.mesh;
-        if mesh.count_vertices() == 0 {
+        if self.triangles.is_empty() || self.total_area <= 0.0 {
             return Default::default();
         }
-        let positions = mesh
-            .attribute(Mesh::ATTRIBUTE_POSITION)
-            .expect("No vertex positions set for `ConvexMesh`");
-        let position: Vec3 = if let VertexAttributeValues::Float32x3(positions) = positions {
-            positions[rng.gen_range(0..positions.len())].into()
-        } else {
-            panic!("Expected a mesh with `Float32x3` positions");
-        };
+        let u = rng.gen_range(0.0..self.total_area);
+        let triangle = self.triangle_for_area(u);
+        let (position, normal) = Self::sample_triangle(triangle, rng);
         let coef = rng.gen_range((1.0 - thickness)..=1.0);
         EmittedParticle {
             position: position * coef,
-            direction: match direction_mode {
-                EmitterDirectionMode::Automatic => (position - self.nominal_center)
-                    .try_normalize()
-                    .unwrap_or(Vec3::Y),
-                EmitterDirectionMode::Fixed(dir) => dir,
-            },
+            direction: self.direction_for(position, normal, direction_mode),
+            ..Default::default()
         }
     }
 
     fn spread_particle(
         &self,
         spread: &mut EmissionSpread,
-        rng: &mut impl Rng,
+        rng: &mut (impl Rng + ?Sized),
         thickness: f32,
         direction_mode: EmitterDirectionMode,
     ) -> EmittedParticle {
-        todo!()
+        if self.triangles.is_empty() || self.total_area <= 0.0 {
+            return Default::default();
+        }
+        let (_, index) = spread.update_index();
+        let u = index.clamp(0.0, 1.0) * self.total_area;
+        let triangle = self.triangle_for_area(u);
+        let (position, normal) = Self::sample_triangle(triangle, rng);
+        let coef = rng.gen_range((1.0 - thickness)..=1.0);
+        EmittedParticle {
+            position: position * coef,
+            direction: self.direction_for(position, normal, direction_mode),
+            ..Default::default()
+        }
     }
 }
 
 impl Default for ConvexMesh {
     fn default() -> Self {
-        Self {
-            mesh: Mesh::from(Cube::default()),
-            nominal_center: Default::default(),
-        }
+        Self::new(Mesh::from(Cube::default()), Vec3::default())
     }
-}
\ No newline at end of file
+}
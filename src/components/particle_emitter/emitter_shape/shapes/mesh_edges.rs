@@ -0,0 +1,162 @@
+use crate::components::particle_emitter::emitter_shape::{EmittedParticle, Emitter};
+use crate::{EmissionSpread, EmitterDirectionMode};
+use bevy::prelude::{shape::Cube, Mesh, Vec3};
+use bevy::render::mesh::{Indices, VertexAttributeValues};
+use rand::Rng;
+
+/// A single mesh edge, cached alongside the running sum of lengths up to and including it,
+/// so [`MeshEdges`] can binary-search a uniform length draw into an edge pick
+#[derive(Debug, Clone, Copy)]
+struct MeshEdge {
+    start: Vec3,
+    end: Vec3,
+    /// Sum of this edge's length and every preceding edge's length
+    cumulative_length: f32,
+}
+
+/// Initializes particles along a mesh's edges (its wireframe), tracing out the triangles'
+/// sides instead of sampling their surface or volume, analogous to Blender's "Create Along Paths"
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "inspector", derive(bevy_inspector_egui::Inspectable))]
+pub struct MeshEdges {
+    /// The mesh object
+    pub mesh: Mesh,
+    /// The *nominal center* of the mesh
+    pub nominal_center: Vec3,
+    /// Length-weighted edge CDF built from `mesh`, cached so it isn't rebuilt every frame
+    #[doc(hidden)]
+    #[cfg_attr(feature = "inspector", inspectable(ignore))]
+    edges: Vec<MeshEdge>,
+    /// Sum of every edge's length, i.e. `edges.last().cumulative_length`
+    #[doc(hidden)]
+    #[cfg_attr(feature = "inspector", inspectable(ignore))]
+    total_length: f32,
+}
+
+impl MeshEdges {
+    /// Creates a new [`MeshEdges`] emitter, pre-computing its length-weighted edge cache
+    pub fn new(mesh: Mesh, nominal_center: Vec3) -> Self {
+        let (edges, total_length) = Self::build_edge_cdf(&mesh);
+        Self {
+            mesh,
+            nominal_center,
+            edges,
+            total_length,
+        }
+    }
+
+    /// Rebuilds the edge CDF from the current `mesh`, e.g. after replacing it
+    pub fn refresh_cache(&mut self) {
+        let (edges, total_length) = Self::build_edge_cdf(&self.mesh);
+        self.edges = edges;
+        self.total_length = total_length;
+    }
+
+    fn build_edge_cdf(mesh: &Mesh) -> (Vec<MeshEdge>, f32) {
+        let Some(positions) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else {
+            return (Vec::new(), 0.0);
+        };
+        let VertexAttributeValues::Float32x3(positions) = positions else {
+            panic!("Expected a mesh with `Float32x3` positions");
+        };
+        let positions: Vec<Vec3> = positions.iter().copied().map(Vec3::from).collect();
+        let indices: Vec<u32> = match mesh.indices() {
+            Some(Indices::U16(indices)) => indices.iter().map(|i| *i as u32).collect(),
+            Some(Indices::U32(indices)) => indices.clone(),
+            None => (0..positions.len() as u32).collect(),
+        };
+        let mut edges = Vec::with_capacity(indices.len());
+        let mut total_length = 0.0;
+        for triangle in indices.chunks_exact(3) {
+            let vertices = [
+                positions[triangle[0] as usize],
+                positions[triangle[1] as usize],
+                positions[triangle[2] as usize],
+            ];
+            for (a, b) in [(0, 1), (1, 2), (2, 0)] {
+                let length = (vertices[b] - vertices[a]).length();
+                if length <= f32::EPSILON {
+                    continue;
+                }
+                total_length += length;
+                edges.push(MeshEdge {
+                    start: vertices[a],
+                    end: vertices[b],
+                    cumulative_length: total_length,
+                });
+            }
+        }
+        (edges, total_length)
+    }
+
+    /// Finds the edge whose cumulative-length bucket contains `u`, for `u` in `[0, total_length)`
+    fn edge_for_length(&self, u: f32) -> &MeshEdge {
+        let index = self
+            .edges
+            .partition_point(|edge| edge.cumulative_length <= u)
+            .min(self.edges.len() - 1);
+        &self.edges[index]
+    }
+
+    fn direction_for(&self, edge: &MeshEdge, direction_mode: EmitterDirectionMode) -> Vec3 {
+        match direction_mode {
+            // `MeshEdges` has no surface normal to speak of, so `SurfaceNormal` falls back to
+            // this shape's own `Automatic` behaviour (the edge tangent), as documented on
+            // `EmitterDirectionMode::SurfaceNormal`.
+            EmitterDirectionMode::Automatic | EmitterDirectionMode::SurfaceNormal => {
+                (edge.end - edge.start).try_normalize().unwrap_or(Vec3::Y)
+            }
+            EmitterDirectionMode::Fixed(dir) => dir,
+        }
+    }
+}
+
+impl Emitter for MeshEdges {
+    fn emit_random_particle(
+        &self,
+        rng: &mut (impl Rng + ?Sized),
+        thickness: f32,
+        direction_mode: EmitterDirectionMode,
+    ) -> EmittedParticle {
+        if self.edges.is_empty() {
+            return Default::default();
+        }
+        let u = rng.gen_range(0.0..self.total_length);
+        let edge = self.edge_for_length(u);
+        let position = edge.start + (edge.end - edge.start) * rng.gen_range(0.0..1.0);
+        let coef = rng.gen_range((1.0 - thickness)..=1.0);
+        EmittedParticle {
+            position: position * coef,
+            direction: self.direction_for(edge, direction_mode),
+            ..Default::default()
+        }
+    }
+
+    fn spread_particle(
+        &self,
+        spread: &mut EmissionSpread,
+        rng: &mut (impl Rng + ?Sized),
+        thickness: f32,
+        direction_mode: EmitterDirectionMode,
+    ) -> EmittedParticle {
+        if self.edges.is_empty() {
+            return Default::default();
+        }
+        let (_, index) = spread.update_index();
+        let u = index.clamp(0.0, 1.0) * self.total_length;
+        let edge = self.edge_for_length(u);
+        let position = edge.start + (edge.end - edge.start) * rng.gen_range(0.0..1.0);
+        let coef = rng.gen_range((1.0 - thickness)..=1.0);
+        EmittedParticle {
+            position: position * coef,
+            direction: self.direction_for(edge, direction_mode),
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for MeshEdges {
+    fn default() -> Self {
+        Self::new(Mesh::from(Cube::default()), Vec3::default())
+    }
+}